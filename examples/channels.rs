@@ -0,0 +1,14 @@
+use coreaudio_volctl::{AudioOutputDevice, MASTER_ELEMENT};
+
+fn main() {
+    let dev = AudioOutputDevice::get_default().unwrap();
+
+    for (channel, vol) in dev.channels().iter().zip(dev.get_channel_volumes().unwrap()) {
+        let label = if *channel == MASTER_ELEMENT {
+            "master".to_string()
+        } else {
+            channel.to_string()
+        };
+        println!("Channel {label}: {vol}%");
+    }
+}
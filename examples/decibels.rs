@@ -0,0 +1,9 @@
+use coreaudio_volctl::AudioOutputDevice;
+
+fn main() {
+    let dev = AudioOutputDevice::get_default().unwrap();
+    let (min, max) = dev.volume_db_range().unwrap();
+
+    println!("Volume range: {min:.1}dB..{max:.1}dB");
+    println!("Current volume: {:.1}dB", dev.get_volume_db().unwrap());
+}
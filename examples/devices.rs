@@ -0,0 +1,8 @@
+use coreaudio_volctl::{list_devices, AudioOutputDevice, Scope};
+
+fn main() {
+    for id in list_devices(Scope::Output).unwrap() {
+        let device = AudioOutputDevice::from_id(id);
+        println!("{id}: {}", device.name().unwrap());
+    }
+}
@@ -6,50 +6,129 @@
     clippy::cast_lossless
 )]
 
+use core_foundation::{base::TCFType, string::CFString};
 use coreaudio_sys::{
-    kAudioObjectSystemObject, AudioDeviceID, AudioObjectPropertyAddress, Float32, UInt32,
+    kAudioObjectPropertyElementMaster, kAudioObjectSystemObject, AudioDeviceID,
+    AudioObjectPropertyAddress, AudioStreamID, AudioValueRange, CFStringRef, Float32, UInt32,
 };
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex};
 
 /// Error type
 pub mod error;
 use error::CAResult;
+mod listener;
+use listener::Listener;
 mod safe_wrappers;
-use safe_wrappers::{get_property, has_property, set_property, Property};
+use safe_wrappers::{
+    get_property, get_property_array, has_property, set_property, translate_property, Property,
+};
 
 const CHANNEL_CHECK_FAILS: usize = 3;
 
-/// Audio output device controller
+/// The signal direction of an audio device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+    /// An input device, e.g. a microphone.
+    Input,
+    /// An output device, e.g. a speaker.
+    Output,
+}
+
+/// The element ID representing a device's master (summed) control, as opposed to an individual
+/// channel. See [`channels()`](AudioDevice::channels) / [`get_channel_volumes()`](AudioDevice::get_channel_volumes).
+pub const MASTER_ELEMENT: u32 = kAudioObjectPropertyElementMaster;
+
+/// Returns the IDs of every currently known audio device that has the given [`Scope`].
 ///
-/// # Note
-/// Changing the default audio output device __after__ an instance is created will not affect it!
-/// You'll need to get a new instance using [`get_default()`](Self::get_default).
-#[derive(Debug)]
-pub struct AudioOutputDevice {
+/// # Errors
+/// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
+/// # Example
+/// ```rust
+/// use coreaudio_volctl::{list_devices, Scope};
+///
+/// fn main() {
+///     for id in list_devices(Scope::Output).unwrap() {
+///         println!("{id}");
+///     }
+/// }
+/// ```
+pub fn list_devices(scope: Scope) -> CAResult<Vec<AudioDeviceID>> {
+    let all_devices =
+        get_property_array::<AudioDeviceID>(kAudioObjectSystemObject, Property::Devices)?;
+
+    Ok(all_devices
+        .into_iter()
+        .filter(|id| AudioDevice::has_streams(*id, scope))
+        .collect())
+}
+
+/// A handle to a registered property-change listener. Dropping it deregisters the listener.
+pub struct ChangeListener(Listener);
+
+/// Registers a callback invoked whenever the system's default device of the given [`Scope`] changes.
+///
+/// # Errors
+/// This method may fail if [`AudioObjectAddPropertyListener`](coreaudio_sys::AudioObjectAddPropertyListener) fails.
+/// # Example
+/// ```rust
+/// use coreaudio_volctl::{on_default_device_change, Scope};
+///
+/// fn main() {
+///     let _listener = on_default_device_change(Scope::Output, |id| {
+///         println!("The default output device is now {id}");
+///     })
+///     .unwrap();
+///     // Keep `_listener` alive for as long as you want to be notified.
+/// }
+/// ```
+pub fn on_default_device_change(
+    scope: Scope,
+    callback: impl FnMut(AudioDeviceID) + Send + 'static,
+) -> CAResult<ChangeListener> {
+    let address: AudioObjectPropertyAddress = Property::GetDefaultDevice(scope).into();
+
+    let listener = listener::add_listener(
+        kAudioObjectSystemObject,
+        address,
+        move || AudioDevice::get_default_device_id(scope),
+        callback,
+    )?;
+
+    Ok(ChangeListener(listener))
+}
+
+/// Shared implementation behind [`AudioOutputDevice`] and [`AudioInputDevice`].
+///
+/// All of the volume/mute logic only depends on a device's ID and [`Scope`], so both public
+/// device types are thin wrappers around this one (accessible through [`Deref`]).
+pub struct AudioDevice {
     device_id: AudioDeviceID,
+    scope: Scope,
     valid_channels: Vec<u32>,
+    listeners: Vec<Listener>,
 }
 
-impl AudioOutputDevice {
-    /// Gets the currently set default audio output device on your system.
-    ///
-    /// # Errors
-    /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
-    /// # Example
-    /// ```rust
-    /// use coreaudio_volctl::AudioOutputDevice;
-    ///
-    /// fn main() {
-    ///     let device = AudioOutputDevice::get_default().unwrap();
-    ///     // ...
-    /// }
-    /// ```
-    pub fn get_default() -> CAResult<Self> {
-        let device_id = Self::get_default_device_id()?;
-        let valid_channels = Self::get_valid_channels(device_id);
+impl std::fmt::Debug for AudioDevice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AudioDevice")
+            .field("device_id", &self.device_id)
+            .field("scope", &self.scope)
+            .field("valid_channels", &self.valid_channels)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AudioDevice {
+    fn get_default(scope: Scope) -> CAResult<Self> {
+        let device_id = Self::get_default_device_id(scope)?;
+        let valid_channels = Self::get_valid_channels(device_id, scope);
 
         Ok(Self {
             device_id,
+            scope,
             valid_channels,
+            listeners: Vec::new(),
         })
     }
 
@@ -63,77 +142,199 @@ impl AudioOutputDevice {
     /// Due to the fact that internally the volume level is requested in scalar units, and later converted to [`u8`](u8), there is
     /// a small precision loss.
     /// Since the volume values have to be requested from each channel individually, their average is computed and returned.
-    /// # Example
-    /// ```rust
-    /// use coreaudio_volctl::AudioOutputDevice;
-    ///
-    /// fn main() {
-    ///     let device = AudioOutputDevice::get_default().unwrap();
-    ///     let volume: u8 = device.get_volume().unwrap();
-    ///     
-    ///     println!("The current volume level is {volume}%");
-    /// }
-    /// ```
     pub fn get_volume(&self) -> CAResult<u8> {
-        let mut address: AudioObjectPropertyAddress = Property::Volume.into();
+        Self::read_volume(self.device_id, self.scope, &self.valid_channels)
+    }
+
+    fn read_volume(device_id: AudioDeviceID, scope: Scope, valid_channels: &[u32]) -> CAResult<u8> {
+        let mut address: AudioObjectPropertyAddress = Property::Volume(scope).into();
         let mut values = Vec::new();
 
-        for channel in &self.valid_channels {
+        for channel in valid_channels {
             address.mElement = *channel;
-            values.push(get_property::<Float32>(self.device_id, address.into())? * 100.0);
+            values.push(get_property::<Float32>(device_id, address.into())? * 100.0);
         }
         let avg = values.iter().sum::<f32>() / values.len() as f32;
 
         Ok(avg as u8)
     }
 
+    /// Gets the currently set volume of the device in decibels (dB), using its native gain units.
+    /// Unlike [`get_volume()`](Self::get_volume), this doesn't lose precision to a `0..=100` scale.
+    ///
+    /// # Errors
+    /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
+    pub fn get_volume_db(&self) -> CAResult<f32> {
+        get_property::<Float32>(self.device_id, Property::VolumeDecibels(self.scope))
+    }
+
+    /// Sets the volume of the device in decibels (dB).
+    ///
+    /// # Errors
+    /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
+    /// # Notes
+    /// `db` is [`clamp()`](f32::clamp)ed to [`volume_db_range()`](Self::volume_db_range), so it's safe to send out-of-range values.
+    pub fn set_volume_db(&self, db: f32) -> CAResult<()> {
+        let (min, max) = self.volume_db_range()?;
+        let db = db.clamp(min, max);
+
+        set_property(self.device_id, Property::VolumeDecibels(self.scope), &db)
+    }
+
+    /// Gets the `(min, max)` decibel range this device's volume can be set to.
+    ///
+    /// # Errors
+    /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
+    pub fn volume_db_range(&self) -> CAResult<(f32, f32)> {
+        // This is a plain "Get" property; the dummy zeroed input is ignored by CoreAudio.
+        let range = translate_property(
+            self.device_id,
+            Property::VolumeRangeDecibels(self.scope),
+            AudioValueRange {
+                mMinimum: 0.0,
+                mMaximum: 0.0,
+            },
+        )?;
+
+        Ok((range.mMinimum as f32, range.mMaximum as f32))
+    }
+
+    /// Converts a scalar volume percentage (`0..=100`) to this device's native decibel units.
+    ///
+    /// # Errors
+    /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
+    pub fn scalar_to_decibels(&self, vol: u8) -> CAResult<f32> {
+        let scalar = vol.clamp(0, 100) as Float32 / 100.0;
+
+        translate_property(
+            self.device_id,
+            Property::ScalarToDecibels(self.scope),
+            scalar,
+        )
+    }
+
+    /// Converts a decibel value to this device's equivalent scalar volume percentage (`0..=100`).
+    ///
+    /// # Errors
+    /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
+    pub fn decibels_to_scalar(&self, db: f32) -> CAResult<u8> {
+        let scalar =
+            translate_property(self.device_id, Property::DecibelsToScalar(self.scope), db)?;
+
+        Ok((scalar * 100.0) as u8)
+    }
+
     /// Gets whether the device is muted or not.
     ///
     /// # Errors
     /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
+    pub fn is_muted(&self) -> CAResult<bool> {
+        Self::read_mute(self.device_id, self.scope)
+    }
+
+    fn read_mute(device_id: AudioDeviceID, scope: Scope) -> CAResult<bool> {
+        Ok(get_property::<i32>(device_id, Property::Mute(scope))? != 0)
+    }
+
+    /// Registers a callback invoked whenever the device's volume changes.
+    /// The listener is kept alive (and deregistered on drop) for as long as this device is.
+    ///
+    /// # Errors
+    /// This method may fail if [`AudioObjectAddPropertyListener`](coreaudio_sys::AudioObjectAddPropertyListener) fails.
     /// # Example
     /// ```rust
     /// use coreaudio_volctl::AudioOutputDevice;
     ///
     /// fn main() {
-    ///     let device = AudioOutputDevice::get_default().unwrap();
-    ///     let muted = device.is_muted().unwrap();
-    ///     
-    ///     if muted {
-    ///         println!("The device is muted.");
-    ///     } else {
-    ///         println!("The device is unmuted.");
-    ///     }
+    ///     let mut device = AudioOutputDevice::get_default().unwrap();
+    ///
+    ///     device
+    ///         .on_volume_change(|vol| println!("Volume is now {vol}%"))
+    ///         .unwrap();
     /// }
     /// ```
-    pub fn is_muted(&self) -> CAResult<bool> {
-        Ok(get_property::<i32>(self.device_id, Property::Mute)? != 0)
+    pub fn on_volume_change(&mut self, callback: impl FnMut(u8) + Send + 'static) -> CAResult<()> {
+        let device_id = self.device_id;
+        let scope = self.scope;
+        let valid_channels = self.valid_channels.clone();
+        let callback = Arc::new(Mutex::new(callback));
+
+        // `get_volume()` averages over every entry of `valid_channels`, which may or may not
+        // include the master element, so a single listener on the master element alone could
+        // both fail to register (device has no master control) and miss per-channel-only changes.
+        // Listen on every channel instead and re-read/re-average on any of them firing.
+        //
+        // Registered listeners are collected locally first and only moved into `self.listeners`
+        // once every channel succeeds, so a failure partway through drops (deregisters) the ones
+        // already registered instead of leaving them live behind a returned `Err`.
+        let mut registered = Vec::new();
+
+        for &channel in &self.valid_channels {
+            let mut address: AudioObjectPropertyAddress = Property::Volume(scope).into();
+            address.mElement = channel;
+            let valid_channels = valid_channels.clone();
+            let callback = Arc::clone(&callback);
+
+            let listener = listener::add_listener(
+                device_id,
+                address,
+                move || Self::read_volume(device_id, scope, &valid_channels),
+                move |vol| {
+                    if let Ok(mut callback) = callback.lock() {
+                        callback(vol);
+                    }
+                },
+            )?;
+            registered.push(listener);
+        }
+
+        self.listeners.extend(registered);
+        Ok(())
+    }
+
+    /// Registers a callback invoked whenever the device's mute status changes.
+    /// The listener is kept alive (and deregistered on drop) for as long as this device is.
+    ///
+    /// # Errors
+    /// This method may fail if [`AudioObjectAddPropertyListener`](coreaudio_sys::AudioObjectAddPropertyListener) fails.
+    pub fn on_mute_change(&mut self, callback: impl FnMut(bool) + Send + 'static) -> CAResult<()> {
+        let device_id = self.device_id;
+        let scope = self.scope;
+        let callback = Arc::new(Mutex::new(callback));
+
+        // See the comment in `on_volume_change()`: `set_mute()` acts on every channel, so a
+        // listener per channel is needed for the same reason, and registrations
+        // are likewise held locally until the whole loop succeeds before joining `self.listeners`.
+        let mut registered = Vec::new();
+
+        for &channel in &self.valid_channels {
+            let mut address: AudioObjectPropertyAddress = Property::Mute(scope).into();
+            address.mElement = channel;
+            let callback = Arc::clone(&callback);
+
+            let listener = listener::add_listener(
+                device_id,
+                address,
+                move || Self::read_mute(device_id, scope),
+                move |muted| {
+                    if let Ok(mut callback) = callback.lock() {
+                        callback(muted);
+                    }
+                },
+            )?;
+            registered.push(listener);
+        }
+
+        self.listeners.extend(registered);
+        Ok(())
     }
 
     /// Sets the mute status of the device.
     ///
     /// # Errors
     /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
-    /// # Example
-    /// ```rust
-    /// use coreaudio_volctl::AudioOutputDevice;
-    /// use std::time::Duration;
-    /// use std::thread::sleep;
-    ///
-    /// fn main() {
-    ///     let device = AudioOutputDevice::get_default().unwrap();
-    ///     
-    ///     device.set_mute(true).unwrap();
-    ///     println!("The device is now muted");
-    ///     
-    ///     sleep(Duration::from_seconds(2));
-    ///
-    ///     device.set_mute(false).unwrap();
-    ///     println!("The device is now unmuted");
-    /// }
-    /// ```
     pub fn set_mute(&self, mute: bool) -> CAResult<()> {
-        let mut address: AudioObjectPropertyAddress = Property::Mute.into();
+        let mut address: AudioObjectPropertyAddress = Property::Mute(self.scope).into();
         let mute = mute as UInt32;
         let mut results = Vec::new();
 
@@ -157,21 +358,9 @@ impl AudioOutputDevice {
     /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
     /// # Notes
     /// `vol` is [`clamp()`](u8::clamp)ed, so it's safe to send values > `100`.
-    /// # Example
-    /// ```rust
-    /// use coreaudio_volctl::AudioOutputDevice;
-    ///
-    /// fn main() {
-    ///     let device = AudioOutputDevice::get_default().unwrap();
-    ///     
-    ///     device.set_volume(25).unwrap(); // Set volume to 25%
-    ///     device.set_volume(50).unwrap(); // Set volume to 50%
-    ///     device.set_volume(100).unwrap(); // Set volume to 100%
-    /// }
-    /// ```
     pub fn set_volume(&self, vol: u8) -> CAResult<()> {
         let vol = vol.clamp(0, 100) as Float32 / 100.0;
-        let mut address: AudioObjectPropertyAddress = Property::Volume.into();
+        let mut address: AudioObjectPropertyAddress = Property::Volume(self.scope).into();
 
         for channel in &self.valid_channels {
             address.mElement = *channel;
@@ -181,39 +370,113 @@ impl AudioOutputDevice {
         Ok(())
     }
 
-    /// Gets whether the device controlled by this instance is the default output device on the system.
-    /// You may create a new instance of [`AudioOutputDevice`](Self) if this returns `false`. This can be useful
-    /// for projects there you need to detect if the default output device was changed.
+    /// The number of individually controllable channels this device exposes volume/mute controls
+    /// for, including the master channel if present.
+    #[must_use]
+    pub fn channel_count(&self) -> usize {
+        self.valid_channels.len()
+    }
+
+    /// The raw channel (element) IDs this device exposes volume/mute controls for, in the order
+    /// [`get_channel_volumes()`](Self::get_channel_volumes) returns their volumes in.
+    /// [`MASTER_ELEMENT`](crate::MASTER_ELEMENT) is the summed master control rather than an
+    /// individual channel.
+    #[must_use]
+    pub fn channels(&self) -> &[u32] {
+        &self.valid_channels
+    }
+
+    /// Gets the volume of each individual channel, in the same order as [`channels()`](Self::channels).
     ///
     /// # Errors
     /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
-    /// # Example
-    /// ```rust
-    /// use coreaudio_volctl::AudioOutputDevice;
+    pub fn get_channel_volumes(&self) -> CAResult<Vec<u8>> {
+        let mut address: AudioObjectPropertyAddress = Property::Volume(self.scope).into();
+        let mut volumes = Vec::with_capacity(self.valid_channels.len());
+
+        for channel in &self.valid_channels {
+            address.mElement = *channel;
+            let vol = get_property::<Float32>(self.device_id, address.into())? * 100.0;
+            volumes.push(vol as u8);
+        }
+
+        Ok(volumes)
+    }
+
+    /// Sets the volume of each individual channel. `volumes` is matched up with
+    /// [`channels()`](Self::channels) pairwise; extra or missing entries are ignored.
     ///
-    /// fn main() {
-    ///     let mut device = AudioOutputDevice::get_default().unwrap();
-    ///     
-    ///     loop {
-    ///         if !device.is_default() {
-    ///             println!("You changed the default output device!");
-    ///         }
-    ///         // Get a new instance so we can control the new device.
-    ///         device = AudioOutputDevice::get_default().unwrap();
-    ///     }
-    /// }
-    /// ```
+    /// # Errors
+    /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
+    /// # Notes
+    /// Each entry of `volumes` is [`clamp()`](u8::clamp)ed, so it's safe to send values > `100`.
+    pub fn set_channel_volumes(&self, volumes: &[u8]) -> CAResult<()> {
+        let mut address: AudioObjectPropertyAddress = Property::Volume(self.scope).into();
+
+        for (channel, vol) in self.valid_channels.iter().zip(volumes) {
+            address.mElement = *channel;
+            let vol = vol.clamp(0, 100) as Float32 / 100.0;
+            set_property(self.device_id, address.into(), &vol)?;
+        }
+
+        Ok(())
+    }
+
+    /// Sets the volume of a single channel (element). Use
+    /// [`MASTER_ELEMENT`](crate::MASTER_ELEMENT) to target the device's master control rather
+    /// than an individual channel.
+    ///
+    /// # Errors
+    /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
+    /// # Notes
+    /// `vol` is [`clamp()`](u8::clamp)ed, so it's safe to send values > `100`.
+    pub fn set_channel_volume(&self, channel: u32, vol: u8) -> CAResult<()> {
+        let mut address: AudioObjectPropertyAddress = Property::Volume(self.scope).into();
+        address.mElement = channel;
+        let vol = vol.clamp(0, 100) as Float32 / 100.0;
+
+        set_property(self.device_id, address.into(), &vol)
+    }
+
+    /// Gets whether the device controlled by this instance is the default device of its [`Scope`] on the system.
+    ///
+    /// Prefer [`on_default_device_change()`] if you want to react to the default device changing;
+    /// it is event-driven and avoids polling this method in a loop.
+    ///
+    /// # Errors
+    /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
     pub fn is_default(&self) -> CAResult<bool> {
-        Ok(Self::get_default_device_id()? == self.device_id)
+        Ok(Self::get_default_device_id(self.scope)? == self.device_id)
     }
 
-    fn get_default_device_id() -> CAResult<AudioDeviceID> {
-        get_property(kAudioObjectSystemObject, Property::GetDefaultOutputDevice)
+    fn from_id(device_id: AudioDeviceID, scope: Scope) -> Self {
+        let valid_channels = Self::get_valid_channels(device_id, scope);
+
+        Self {
+            device_id,
+            scope,
+            valid_channels,
+            listeners: Vec::new(),
+        }
     }
 
-    fn get_valid_channels(id: AudioDeviceID) -> Vec<u32> {
+    /// Gets the human-readable name of the device, e.g. `"MacBook Pro Speakers"`.
+    ///
+    /// # Errors
+    /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
+    pub fn name(&self) -> CAResult<String> {
+        let name: CFStringRef =
+            translate_property(self.device_id, Property::Name, std::ptr::null())?;
+        Ok(unsafe { CFString::wrap_under_create_rule(name) }.to_string())
+    }
+
+    fn get_default_device_id(scope: Scope) -> CAResult<AudioDeviceID> {
+        get_property(kAudioObjectSystemObject, Property::GetDefaultDevice(scope))
+    }
+
+    fn get_valid_channels(id: AudioDeviceID, scope: Scope) -> Vec<u32> {
         let mut result = Vec::new();
-        let mut address: AudioObjectPropertyAddress = Property::Volume.into();
+        let mut address: AudioObjectPropertyAddress = Property::Volume(scope).into();
         let mut failures = 0;
 
         while failures < CHANNEL_CHECK_FAILS {
@@ -227,4 +490,118 @@ impl AudioOutputDevice {
 
         result
     }
+
+    /// Whether `id` exposes any streams in `scope`, i.e. whether it's a genuine member of that
+    /// scope regardless of whether it also has a software volume control.
+    fn has_streams(id: AudioDeviceID, scope: Scope) -> bool {
+        get_property_array::<AudioStreamID>(id, Property::Streams(scope))
+            .map(|streams| !streams.is_empty())
+            .unwrap_or(false)
+    }
+}
+
+/// Audio output device controller
+///
+/// # Note
+/// Changing the default audio output device __after__ an instance is created will not affect it!
+/// You'll need to get a new instance using [`get_default()`](Self::get_default).
+#[derive(Debug)]
+pub struct AudioOutputDevice(AudioDevice);
+
+impl AudioOutputDevice {
+    /// Gets the currently set default audio output device on your system.
+    ///
+    /// # Errors
+    /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
+    /// # Example
+    /// ```rust
+    /// use coreaudio_volctl::AudioOutputDevice;
+    ///
+    /// fn main() {
+    ///     let device = AudioOutputDevice::get_default().unwrap();
+    ///     // ...
+    /// }
+    /// ```
+    pub fn get_default() -> CAResult<Self> {
+        Ok(Self(AudioDevice::get_default(Scope::Output)?))
+    }
+
+    /// Builds a controller for a specific output device, rather than the system default.
+    /// Use [`list_devices`] to discover the IDs of the devices available on the system.
+    /// # Example
+    /// ```rust
+    /// use coreaudio_volctl::{list_devices, AudioOutputDevice, Scope};
+    ///
+    /// fn main() {
+    ///     let id = list_devices(Scope::Output).unwrap()[0];
+    ///     let device = AudioOutputDevice::from_id(id);
+    ///     // ...
+    /// }
+    /// ```
+    #[must_use]
+    pub fn from_id(device_id: AudioDeviceID) -> Self {
+        Self(AudioDevice::from_id(device_id, Scope::Output))
+    }
+}
+
+impl Deref for AudioOutputDevice {
+    type Target = AudioDevice;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AudioOutputDevice {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Audio input device controller, e.g. a microphone.
+///
+/// # Note
+/// Changing the default audio input device __after__ an instance is created will not affect it!
+/// You'll need to get a new instance using [`get_default()`](Self::get_default).
+#[derive(Debug)]
+pub struct AudioInputDevice(AudioDevice);
+
+impl AudioInputDevice {
+    /// Gets the currently set default audio input device on your system.
+    ///
+    /// # Errors
+    /// This method may fail if [`AudioObjectGetPropertyData`](coreaudio_sys::AudioObjectGetPropertyData) fails.
+    /// # Example
+    /// ```rust
+    /// use coreaudio_volctl::AudioInputDevice;
+    ///
+    /// fn main() {
+    ///     let device = AudioInputDevice::get_default().unwrap();
+    ///     // ...
+    /// }
+    /// ```
+    pub fn get_default() -> CAResult<Self> {
+        Ok(Self(AudioDevice::get_default(Scope::Input)?))
+    }
+
+    /// Builds a controller for a specific input device, rather than the system default.
+    /// Use [`list_devices`] to discover the IDs of the devices available on the system.
+    #[must_use]
+    pub fn from_id(device_id: AudioDeviceID) -> Self {
+        Self(AudioDevice::from_id(device_id, Scope::Input))
+    }
+}
+
+impl Deref for AudioInputDevice {
+    type Target = AudioDevice;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AudioInputDevice {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
 }
@@ -0,0 +1,92 @@
+use crate::error::CAResult;
+use coreaudio_sys::{
+    kAudioHardwareNoError, AudioObjectAddPropertyListener, AudioObjectID,
+    AudioObjectPropertyAddress, AudioObjectPropertyListenerProc, AudioObjectRemovePropertyListener,
+    OSStatus, UInt32,
+};
+use std::ffi::c_void;
+
+/// A registered CoreAudio property listener.
+///
+/// Owns the boxed callback (and everything it needs to re-read the changed property) so it stays
+/// alive for as long as CoreAudio may invoke it, and deregisters itself on [`Drop`].
+pub(crate) struct Listener {
+    device_id: AudioObjectID,
+    address: AudioObjectPropertyAddress,
+    proc: AudioObjectPropertyListenerProc,
+    ctx: *mut c_void,
+    drop_ctx: unsafe fn(*mut c_void),
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        unsafe {
+            AudioObjectRemovePropertyListener(self.device_id, &self.address, self.proc, self.ctx);
+            (self.drop_ctx)(self.ctx);
+        }
+    }
+}
+
+// SAFETY: `ctx` points to a boxed `ListenerContext<T, F>` whose `read` and `callback` fields are
+// both required to be `Send` by `add_listener`'s bounds, so sending the `Listener` (and thus the
+// pointer to that context) across threads is sound even though `*mut c_void` isn't `Send` itself.
+unsafe impl Send for Listener {}
+
+struct ListenerContext<T, F> {
+    read: Box<dyn Fn() -> CAResult<T> + Send>,
+    callback: F,
+}
+
+unsafe extern "C" fn trampoline<T, F: FnMut(T)>(
+    _in_object_id: AudioObjectID,
+    _in_number_addresses: UInt32,
+    _in_addresses: *const AudioObjectPropertyAddress,
+    in_client_data: *mut c_void,
+) -> OSStatus {
+    let ctx = &mut *(in_client_data as *mut ListenerContext<T, F>);
+
+    if let Ok(value) = (ctx.read)() {
+        (ctx.callback)(value);
+    }
+
+    kAudioHardwareNoError as OSStatus
+}
+
+unsafe fn drop_ctx<T, F>(ptr: *mut c_void) {
+    drop(Box::from_raw(ptr as *mut ListenerContext<T, F>));
+}
+
+/// Registers `callback` to run on `address` changes, re-reading the new value with `read`
+/// before invoking it. Returns a [`Listener`] that deregisters the callback when dropped.
+///
+/// # Notes
+/// `callback` and `read` must be [`Send`]: CoreAudio invokes the listener from its own
+/// notification thread, which is not necessarily the thread that registered it.
+pub(crate) fn add_listener<T: 'static, F: FnMut(T) + Send + 'static>(
+    device_id: AudioObjectID,
+    address: AudioObjectPropertyAddress,
+    read: impl Fn() -> CAResult<T> + Send + 'static,
+    callback: F,
+) -> CAResult<Listener> {
+    let ctx = Box::new(ListenerContext {
+        read: Box::new(read),
+        callback,
+    });
+    let ctx = Box::into_raw(ctx) as *mut c_void;
+    let proc: AudioObjectPropertyListenerProc = Some(trampoline::<T, F>);
+
+    let status = unsafe { AudioObjectAddPropertyListener(device_id, &address, proc, ctx) } as u32;
+
+    if status != kAudioHardwareNoError {
+        unsafe { drop_ctx::<T, F>(ctx) };
+        return Err(status as OSStatus);
+    }
+
+    Ok(Listener {
+        device_id,
+        address,
+        proc,
+        ctx,
+        drop_ctx: drop_ctx::<T, F>,
+    })
+}
@@ -1,22 +1,43 @@
 use crate::error::CAResult;
+use crate::Scope;
 use core::mem::size_of;
 use coreaudio_sys::{
-    kAudioDevicePropertyMute, kAudioDevicePropertyScopeOutput, kAudioDevicePropertyVolumeScalar,
-    kAudioHardwareNoError, kAudioHardwarePropertyDefaultOutputDevice,
-    kAudioObjectPropertyElementMaster, kAudioObjectPropertyScopeGlobal, AudioDeviceID,
-    AudioObjectGetPropertyData, AudioObjectHasProperty, AudioObjectPropertyAddress,
-    AudioObjectSetPropertyData, OSStatus, UInt32,
+    kAudioDevicePropertyMute, kAudioDevicePropertyScopeInput, kAudioDevicePropertyScopeOutput,
+    kAudioDevicePropertyStreams, kAudioDevicePropertyVolumeDecibels,
+    kAudioDevicePropertyVolumeDecibelsToScalar, kAudioDevicePropertyVolumeRangeDecibels,
+    kAudioDevicePropertyVolumeScalar, kAudioDevicePropertyVolumeScalarToDecibels,
+    kAudioHardwareNoError, kAudioHardwarePropertyDefaultInputDevice,
+    kAudioHardwarePropertyDefaultOutputDevice, kAudioHardwarePropertyDevices,
+    kAudioObjectPropertyElementMaster, kAudioObjectPropertyName, kAudioObjectPropertyScopeGlobal,
+    AudioDeviceID, AudioObjectGetPropertyData, AudioObjectGetPropertyDataSize,
+    AudioObjectHasProperty, AudioObjectPropertyAddress, AudioObjectSetPropertyData, OSStatus,
+    UInt32,
 };
 use std::{
     ffi::c_void,
-    ptr::{addr_of, null},
+    ptr::{addr_of, addr_of_mut, null},
 };
 
 #[derive(Debug, Clone, Copy)]
 pub enum Property {
-    Volume,
-    Mute,
-    GetDefaultOutputDevice,
+    Volume(Scope),
+    Mute(Scope),
+    GetDefaultDevice(Scope),
+    /// All devices currently known to the system, queried on [`coreaudio_sys::kAudioObjectSystemObject`].
+    Devices,
+    /// The human-readable name of a device.
+    Name,
+    /// The stream IDs a device exposes in a given [`Scope`]. A device belongs to a scope if and
+    /// only if this is non-empty, regardless of whether it also has a software volume control.
+    Streams(Scope),
+    /// The device's volume in decibels, using its native gain units.
+    VolumeDecibels(Scope),
+    /// The `(min, max)` decibel range the device's volume can be set to.
+    VolumeRangeDecibels(Scope),
+    /// A "translation" property: converts a scalar volume (`0.0..=1.0`) to decibels.
+    ScalarToDecibels(Scope),
+    /// A "translation" property: converts a decibel value to a scalar volume (`0.0..=1.0`).
+    DecibelsToScalar(Scope),
     Custom(AudioObjectPropertyAddress),
 }
 
@@ -54,24 +75,125 @@ pub fn has_property(device_id: AudioDeviceID, property: Property) -> bool {
     ret != 0
 }
 
+/// Like [`get_property`], but for properties whose data is a variable-length array rather than
+/// a single value of a known size. The element count is derived from the size CoreAudio reports
+/// via `AudioObjectGetPropertyDataSize`.
+pub fn get_property_array<T: Default + Clone>(
+    device_id: AudioDeviceID,
+    property: Property,
+) -> CAResult<Vec<T>> {
+    let address: AudioObjectPropertyAddress = property.into();
+    let mut data_size: UInt32 = 0;
+
+    let status =
+        unsafe { AudioObjectGetPropertyDataSize(device_id, &address, 0, null(), &mut data_size) }
+            as u32;
+    if status != kAudioHardwareNoError {
+        return Err(status as OSStatus);
+    }
+
+    let count = data_size as usize / size_of::<T>();
+    let mut result = vec![T::default(); count];
+    let ptr = result.as_mut_ptr() as *mut c_void;
+
+    let status =
+        unsafe { AudioObjectGetPropertyData(device_id, &address, 0, null(), &mut data_size, ptr) }
+            as u32;
+    if status != kAudioHardwareNoError {
+        return Err(status as OSStatus);
+    }
+
+    Ok(result)
+}
+
+/// Like [`get_property`], but for "translation" properties that read an input value out of the
+/// same buffer they write their output into (e.g. [`Property::ScalarToDecibels`]). Also usable
+/// for plain "Get" properties whose result type doesn't implement [`Default`], since `input` is
+/// simply ignored by CoreAudio in that case.
+pub fn translate_property<T: Copy>(
+    device_id: AudioDeviceID,
+    property: Property,
+    input: T,
+) -> CAResult<T> {
+    let mut value = input;
+    let ptr = addr_of_mut!(value) as *mut c_void;
+    let mut data_size = size_of::<T>() as UInt32;
+
+    let status = unsafe {
+        AudioObjectGetPropertyData(device_id, &property.into(), 0, null(), &mut data_size, ptr)
+    } as u32;
+
+    if status != kAudioHardwareNoError {
+        return Err(status as OSStatus);
+    }
+    Ok(value)
+}
+
+impl From<Scope> for UInt32 {
+    fn from(value: Scope) -> Self {
+        match value {
+            Scope::Input => kAudioDevicePropertyScopeInput,
+            Scope::Output => kAudioDevicePropertyScopeOutput,
+        }
+    }
+}
+
 impl From<Property> for AudioObjectPropertyAddress {
     fn from(value: Property) -> Self {
         match value {
-            Property::Volume => Self {
+            Property::Volume(scope) => Self {
                 mSelector: kAudioDevicePropertyVolumeScalar,
-                mScope: kAudioDevicePropertyScopeOutput,
+                mScope: scope.into(),
                 mElement: 0,
             },
-            Property::Mute => Self {
+            Property::Mute(scope) => Self {
                 mSelector: kAudioDevicePropertyMute,
-                mScope: kAudioDevicePropertyScopeOutput,
+                mScope: scope.into(),
                 mElement: 0,
             },
-            Property::GetDefaultOutputDevice => Self {
-                mSelector: kAudioHardwarePropertyDefaultOutputDevice,
+            Property::GetDefaultDevice(scope) => Self {
+                mSelector: match scope {
+                    Scope::Input => kAudioHardwarePropertyDefaultInputDevice,
+                    Scope::Output => kAudioHardwarePropertyDefaultOutputDevice,
+                },
+                mScope: kAudioObjectPropertyScopeGlobal,
+                mElement: kAudioObjectPropertyElementMaster,
+            },
+            Property::Devices => Self {
+                mSelector: kAudioHardwarePropertyDevices,
+                mScope: kAudioObjectPropertyScopeGlobal,
+                mElement: kAudioObjectPropertyElementMaster,
+            },
+            Property::Name => Self {
+                mSelector: kAudioObjectPropertyName,
                 mScope: kAudioObjectPropertyScopeGlobal,
                 mElement: kAudioObjectPropertyElementMaster,
             },
+            Property::Streams(scope) => Self {
+                mSelector: kAudioDevicePropertyStreams,
+                mScope: scope.into(),
+                mElement: 0,
+            },
+            Property::VolumeDecibels(scope) => Self {
+                mSelector: kAudioDevicePropertyVolumeDecibels,
+                mScope: scope.into(),
+                mElement: 0,
+            },
+            Property::VolumeRangeDecibels(scope) => Self {
+                mSelector: kAudioDevicePropertyVolumeRangeDecibels,
+                mScope: scope.into(),
+                mElement: 0,
+            },
+            Property::ScalarToDecibels(scope) => Self {
+                mSelector: kAudioDevicePropertyVolumeScalarToDecibels,
+                mScope: scope.into(),
+                mElement: 0,
+            },
+            Property::DecibelsToScalar(scope) => Self {
+                mSelector: kAudioDevicePropertyVolumeDecibelsToScalar,
+                mScope: scope.into(),
+                mElement: 0,
+            },
             Property::Custom(addr) => addr,
         }
     }